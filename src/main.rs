@@ -1,19 +1,29 @@
 extern crate preferences;
+use chrono::{Local, NaiveDate};
 use iced::widget::{
-    button, center, container, mouse_area, opaque, progress_bar, row, stack, text, Column,
-    Container, Row, Text,
+    button, center, container, mouse_area, opaque, progress_bar, row, stack, text, text_input,
+    Column, Container, Row, Text,
 };
 use iced::{keyboard, time, Center, Color, Element, Length, Subscription, Theme};
 use notify_rust::Notification;
 use preferences::{AppInfo, Preferences};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
 use serde::{Deserialize, Serialize};
-use std::time::{Duration, SystemTime};
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::time::{Duration, Instant};
 
 const APP_INFO: AppInfo = AppInfo {
     name: "Earlygirl",
     author: "Earlygirl",
 };
 const PREFS_KEY: &str = "earlygirl_preferences";
+const STATS_KEY: &str = "earlygirl_stats";
+
+const CHIME_WORK_END: &[u8] = include_bytes!("../assets/work_end_chime.wav");
+const CHIME_BREAK_END: &[u8] = include_bytes!("../assets/break_end_chime.wav");
+const BELL_WORK_END: &[u8] = include_bytes!("../assets/work_end_bell.wav");
+const BELL_BREAK_END: &[u8] = include_bytes!("../assets/break_end_bell.wav");
 
 fn main() -> iced::Result {
     let window_settings = iced::window::Settings {
@@ -29,12 +39,65 @@ fn main() -> iced::Result {
         .run()
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+struct Stats {
+    by_date: HashMap<String, DayStats>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Clone, Copy)]
+struct DayStats {
+    completed_work_sessions: u32,
+    total_focused_seconds: f64,
+}
+
+impl Stats {
+    fn record_completed_work_session(&mut self, focused_seconds: f64) {
+        let today = Local::now().date_naive().to_string();
+        let entry = self.by_date.entry(today).or_default();
+        entry.completed_work_sessions += 1;
+        entry.total_focused_seconds += focused_seconds;
+    }
+
+    fn today_count(&self) -> u32 {
+        let today = Local::now().date_naive().to_string();
+        self.by_date
+            .get(&today)
+            .map_or(0, |day| day.completed_work_sessions)
+    }
+
+    fn last_seven_days_count(&self) -> u32 {
+        let today = Local::now().date_naive();
+        self.by_date
+            .iter()
+            .filter_map(|(date, day)| {
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .ok()
+                    .map(|date| (date, day))
+            })
+            .filter(|(date, _)| (today - *date).num_days() < 7)
+            .map(|(_, day)| day.completed_work_sessions)
+            .sum()
+    }
+
+    fn all_time_count(&self) -> u32 {
+        self.by_date.values().map(|day| day.completed_work_sessions).sum()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
 struct EarlyGirlPreferences {
     work_interval: f64,
     break_interval: f64,
+    long_break_interval: f64,
+    pomodoros_until_long_break: u32,
     auto_start_work: bool,
     auto_start_break: bool,
+    sound_enabled: bool,
+    sound_volume: f64,
+    sound_theme: SoundTheme,
+    postpone_duration: f64,
+    max_postpones: u32,
+    notifier: NotifierKind,
 }
 
 impl Default for EarlyGirlPreferences {
@@ -42,12 +105,96 @@ impl Default for EarlyGirlPreferences {
         Self {
             work_interval: 45.0 * 60.0,
             break_interval: 15.0 * 60.0,
+            long_break_interval: 30.0 * 60.0,
+            pomodoros_until_long_break: 4,
             auto_start_work: false,
             auto_start_break: false,
+            sound_enabled: true,
+            sound_volume: 0.5,
+            sound_theme: SoundTheme::Chime,
+            postpone_duration: 5.0 * 60.0,
+            max_postpones: 3,
+            notifier: NotifierKind::Desktop,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+enum NotifierKind {
+    Desktop,
+    Command { template: String },
+    None,
+}
+
+const DEFAULT_NOTIFIER_COMMAND_TEMPLATE: &str = "notify-send '{msg}'";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotifierKindTag {
+    Desktop,
+    Command,
+    None,
+}
+
+impl NotifierKindTag {
+    const ALL: [NotifierKindTag; 3] = [
+        NotifierKindTag::Desktop,
+        NotifierKindTag::Command,
+        NotifierKindTag::None,
+    ];
+}
+
+impl From<&NotifierKind> for NotifierKindTag {
+    fn from(kind: &NotifierKind) -> Self {
+        match kind {
+            NotifierKind::Desktop => NotifierKindTag::Desktop,
+            NotifierKind::Command { .. } => NotifierKindTag::Command,
+            NotifierKind::None => NotifierKindTag::None,
+        }
+    }
+}
+
+impl std::fmt::Display for NotifierKindTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            NotifierKindTag::Desktop => "Desktop",
+            NotifierKindTag::Command => "Command",
+            NotifierKindTag::None => "None",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+enum SoundTheme {
+    Chime,
+    Bell,
+}
+
+impl SoundTheme {
+    const ALL: [SoundTheme; 2] = [SoundTheme::Chime, SoundTheme::Bell];
+
+    fn clip_for(self, timer_type: &TimerType) -> &'static [u8] {
+        match (self, timer_type) {
+            (SoundTheme::Chime, TimerType::WorkTime) => CHIME_WORK_END,
+            (SoundTheme::Chime, TimerType::BreakTime | TimerType::LongBreakTime) => {
+                CHIME_BREAK_END
+            }
+            (SoundTheme::Bell, TimerType::WorkTime) => BELL_WORK_END,
+            (SoundTheme::Bell, TimerType::BreakTime | TimerType::LongBreakTime) => BELL_BREAK_END,
         }
     }
 }
 
+impl std::fmt::Display for SoundTheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SoundTheme::Chime => "Chime",
+            SoundTheme::Bell => "Bell",
+        };
+        write!(f, "{name}")
+    }
+}
+
 struct Earlygirl {
     theme: Theme,
     current_timer_duration: f64,
@@ -56,6 +203,12 @@ struct Earlygirl {
     timer_state: TimerState,
     preferences: EarlyGirlPreferences,
     show_modal: bool,
+    show_stats_modal: bool,
+    completed_work_sessions: u32,
+    postpone_count: u32,
+    stats: Stats,
+    _audio_stream: Option<OutputStream>,
+    audio_handle: Option<OutputStreamHandle>,
 }
 
 impl Default for Earlygirl {
@@ -68,6 +221,7 @@ impl Default for Earlygirl {
 enum TimerType {
     WorkTime,
     BreakTime,
+    LongBreakTime,
 }
 
 #[derive(Default)]
@@ -75,31 +229,48 @@ enum TimerState {
     #[default]
     Idle,
     Ticking {
-        last_tick: SystemTime,
+        last_tick: Instant,
     },
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     Toggle,
-    Tick(SystemTime),
+    Tick(Instant),
     ToggleSettings,
     WorkIntervalChanged(f64),
     BreakIntervalChanged(f64),
     AutoStartWorkChanged(bool),
     AutoStartBreakChanged(bool),
+    LongBreakIntervalChanged(f64),
+    PomodorosUntilLongBreakChanged(u32),
+    SoundEnabledChanged(bool),
+    SoundVolumeChanged(f64),
+    SoundThemeChanged(SoundTheme),
+    PostponeDurationChanged(f64),
+    MaxPostponesChanged(u32),
+    NotifierKindChanged(NotifierKindTag),
+    NotifierCommandTemplateChanged(String),
+    Postpone,
     Reset,
     SwitchWorkType,
+    ToggleStats,
 }
 
 impl Earlygirl {
     fn new() -> Self {
         let preferences = EarlyGirlPreferences::load(&APP_INFO, PREFS_KEY).unwrap_or_default();
+        let stats = Stats::load(&APP_INFO, STATS_KEY).unwrap_or_default();
 
         let timer_state = TimerState::Idle;
         let timer_type = TimerType::WorkTime;
         let interval = preferences.work_interval;
 
+        let (audio_stream, audio_handle) = match OutputStream::try_default() {
+            Ok((stream, handle)) => (Some(stream), Some(handle)),
+            Err(_) => (None, None),
+        };
+
         Self {
             theme: Theme::default(),
             current_timer_duration: 0.0,
@@ -108,6 +279,12 @@ impl Earlygirl {
             timer_state,
             preferences,
             show_modal: false,
+            show_stats_modal: false,
+            completed_work_sessions: 0,
+            postpone_count: 0,
+            stats,
+            _audio_stream: audio_stream,
+            audio_handle,
         }
     }
 
@@ -120,7 +297,7 @@ impl Earlygirl {
             Message::Toggle => match self.timer_state {
                 TimerState::Idle => {
                     self.timer_state = TimerState::Ticking {
-                        last_tick: SystemTime::now(),
+                        last_tick: Instant::now(),
                     };
                     self.current_timer_duration = 0.0;
                     self.set_interval_for_work_type()
@@ -131,15 +308,25 @@ impl Earlygirl {
             },
             Message::Tick(now) => {
                 if let TimerState::Ticking { last_tick } = &mut self.timer_state {
-                    if let Ok(time_elapsed) = now.duration_since(*last_tick) {
-                        let elapsed_secs = time_elapsed.as_secs_f64();
-                        self.current_timer_duration += elapsed_secs;
-                        *last_tick = now;
-                    }
+                    let time_elapsed = now.duration_since(*last_tick);
+                    self.current_timer_duration += time_elapsed.as_secs_f64();
+                    *last_tick = now;
 
                     if self.current_timer_duration >= self.interval {
                         self.send_notification();
-                        self.toggle_work_type();
+                        if matches!(self.timer_type, TimerType::WorkTime) {
+                            self.completed_work_sessions += 1;
+                            self.stats
+                                .record_completed_work_session(self.current_timer_duration);
+                            self.write_stats();
+                            if self.preferences.auto_start_break {
+                                self.toggle_work_type();
+                            } else {
+                                self.timer_state = TimerState::Idle;
+                            }
+                        } else {
+                            self.toggle_work_type();
+                        }
                     };
                 }
             }
@@ -153,9 +340,56 @@ impl Earlygirl {
                 self.write_preferences();
                 self.set_interval_for_work_type();
             }
+            Message::LongBreakIntervalChanged(new_interval) => {
+                self.preferences.long_break_interval = new_interval * 60.0;
+                self.write_preferences();
+                self.set_interval_for_work_type();
+            }
+            Message::PomodorosUntilLongBreakChanged(new_count) => {
+                self.preferences.pomodoros_until_long_break = new_count;
+                self.write_preferences();
+            }
+            Message::SoundEnabledChanged(new_value) => {
+                self.preferences.sound_enabled = new_value;
+                self.write_preferences();
+            }
+            Message::SoundVolumeChanged(new_volume) => {
+                self.preferences.sound_volume = new_volume;
+                self.write_preferences();
+            }
+            Message::SoundThemeChanged(new_theme) => {
+                self.preferences.sound_theme = new_theme;
+                self.write_preferences();
+            }
+            Message::PostponeDurationChanged(new_duration) => {
+                self.preferences.postpone_duration = new_duration * 60.0;
+                self.write_preferences();
+            }
+            Message::MaxPostponesChanged(new_max) => {
+                self.preferences.max_postpones = new_max;
+                self.write_preferences();
+            }
+            Message::NotifierKindChanged(new_kind) => {
+                self.preferences.notifier = match new_kind {
+                    NotifierKindTag::Desktop => NotifierKind::Desktop,
+                    NotifierKindTag::Command => NotifierKind::Command {
+                        template: DEFAULT_NOTIFIER_COMMAND_TEMPLATE.to_string(),
+                    },
+                    NotifierKindTag::None => NotifierKind::None,
+                };
+                self.write_preferences();
+            }
+            Message::NotifierCommandTemplateChanged(new_template) => {
+                self.preferences.notifier = NotifierKind::Command {
+                    template: new_template,
+                };
+                self.write_preferences();
+            }
+            Message::Postpone => self.postpone_work_session(),
             Message::Reset => self.reset_timer(),
             Message::SwitchWorkType => self.toggle_work_type(),
             Message::ToggleSettings => self.show_modal = !self.show_modal,
+            Message::ToggleStats => self.show_stats_modal = !self.show_stats_modal,
             Message::AutoStartWorkChanged(new_value) => {
                 self.preferences.auto_start_work = new_value;
                 self.write_preferences();
@@ -171,29 +405,87 @@ impl Earlygirl {
         let message = match self.timer_type {
             TimerType::WorkTime => "Time to get back to work!",
             TimerType::BreakTime => "Time for a break!",
+            TimerType::LongBreakTime => "Time for a long break!",
+        };
+        match &self.preferences.notifier {
+            NotifierKind::Desktop => {
+                let _ = Notification::new()
+                    .summary(message)
+                    .appname("Earlygirl")
+                    .show();
+            }
+            NotifierKind::Command { template } => {
+                let command = template.replace("{msg}", message);
+                let _ = std::process::Command::new("sh").arg("-c").arg(command).spawn();
+            }
+            NotifierKind::None => {}
+        }
+
+        self.play_sound();
+    }
+
+    fn play_sound(&self) {
+        if !self.preferences.sound_enabled {
+            return;
+        }
+
+        let Some(audio_handle) = &self.audio_handle else {
+            return;
+        };
+        let Ok(sink) = Sink::try_new(audio_handle) else {
+            return;
+        };
+        let clip = self.preferences.sound_theme.clip_for(&self.timer_type);
+        let Ok(source) = Decoder::new(Cursor::new(clip)) else {
+            return;
         };
-        let _ = Notification::new()
-            .summary(message)
-            .appname("Earlygirl")
-            .show();
+        sink.set_volume(self.preferences.sound_volume as f32);
+        sink.append(source);
+        sink.detach();
     }
 
     fn reset_timer(&mut self) {
         self.timer_state = TimerState::Idle;
         self.current_timer_duration = 0.0;
+        self.completed_work_sessions = 0;
         self.set_interval_for_work_type();
     }
 
+    fn postpone_work_session(&mut self) {
+        if !matches!(self.timer_type, TimerType::WorkTime)
+            || self.current_timer_duration < self.interval
+            || self.postpone_count >= self.preferences.max_postpones
+        {
+            return;
+        }
+
+        self.interval += self.preferences.postpone_duration;
+        self.postpone_count += 1;
+        self.timer_state = TimerState::Ticking {
+            last_tick: Instant::now(),
+        };
+    }
+
     fn toggle_work_type(&mut self) {
+        self.postpone_count = 0;
         match self.timer_type {
             TimerType::WorkTime => {
-                self.timer_type = TimerType::BreakTime;
-                self.interval = self.preferences.break_interval;
+                let pomodoros_until_long_break =
+                    self.preferences.pomodoros_until_long_break.max(1);
+                if self.completed_work_sessions != 0
+                    && self.completed_work_sessions % pomodoros_until_long_break == 0
+                {
+                    self.timer_type = TimerType::LongBreakTime;
+                    self.interval = self.preferences.long_break_interval;
+                } else {
+                    self.timer_type = TimerType::BreakTime;
+                    self.interval = self.preferences.break_interval;
+                }
                 if !self.preferences.auto_start_break {
                     self.timer_state = TimerState::Idle;
                 }
             }
-            TimerType::BreakTime => {
+            TimerType::BreakTime | TimerType::LongBreakTime => {
                 self.timer_type = TimerType::WorkTime;
                 self.interval = self.preferences.work_interval;
                 if !self.preferences.auto_start_work {
@@ -208,6 +500,7 @@ impl Earlygirl {
         match self.timer_type {
             TimerType::WorkTime => self.interval = self.preferences.work_interval,
             TimerType::BreakTime => self.interval = self.preferences.break_interval,
+            TimerType::LongBreakTime => self.interval = self.preferences.long_break_interval,
         }
     }
 
@@ -216,11 +509,34 @@ impl Earlygirl {
         assert!(save_result.is_ok());
     }
 
+    fn write_stats(&self) {
+        let save_result = self.stats.save(&APP_INFO, STATS_KEY);
+        assert!(save_result.is_ok());
+    }
+
+    fn stats_modal(&self) -> Element<Message> {
+        let close_button = button("Close").on_press(Message::ToggleStats);
+        Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(Text::new(format!("Today: {}", self.stats.today_count())))
+            .push(Text::new(format!(
+                "Last 7 days: {}",
+                self.stats.last_seven_days_count()
+            )))
+            .push(Text::new(format!(
+                "All time: {}",
+                self.stats.all_time_count()
+            )))
+            .push(close_button)
+            .into()
+    }
+
     fn subscription(&self) -> Subscription<Message> {
         let tick = match self.timer_state {
             TimerState::Idle => Subscription::none(),
             TimerState::Ticking { .. } => {
-                time::every(Duration::from_millis(10)).map(|_| Message::Tick(SystemTime::now()))
+                time::every(Duration::from_secs(1)).map(|_| Message::Tick(Instant::now()))
             }
         };
 
@@ -230,6 +546,7 @@ impl Earlygirl {
             match key.as_ref() {
                 keyboard::Key::Named(key::Named::Space) => Some(Message::Toggle),
                 keyboard::Key::Character("r") => Some(Message::Reset),
+                keyboard::Key::Character("p") => Some(Message::Postpone),
                 _ => None,
             }
         }
@@ -253,6 +570,26 @@ impl Earlygirl {
         )
         .step(5)
         .width(200);
+        let long_break_slider = iced::widget::slider(
+            5.0..=60.0,
+            self.preferences.long_break_interval / MINUTE,
+            Message::LongBreakIntervalChanged,
+        )
+        .step(5)
+        .width(200);
+
+        let pomodoros_until_long_break = self.preferences.pomodoros_until_long_break;
+        let round_size_stepper = row![
+            button("-").on_press(Message::PomodorosUntilLongBreakChanged(
+                pomodoros_until_long_break.saturating_sub(1).max(1)
+            )),
+            Text::new(format!("{pomodoros_until_long_break}")),
+            button("+").on_press(Message::PomodorosUntilLongBreakChanged(
+                pomodoros_until_long_break + 1
+            )),
+        ]
+        .spacing(10)
+        .align_y(Center);
 
         let auto_start_work =
             iced::widget::checkbox("Auto start work", self.preferences.auto_start_work)
@@ -261,10 +598,62 @@ impl Earlygirl {
         let auto_start_break =
             iced::widget::checkbox("Auto start break", self.preferences.auto_start_break)
                 .on_toggle(Message::AutoStartBreakChanged);
+
+        let sound_enabled =
+            iced::widget::checkbox("Play sound", self.preferences.sound_enabled)
+                .on_toggle(Message::SoundEnabledChanged);
+        let sound_volume_slider = iced::widget::slider(
+            0.0..=1.0,
+            self.preferences.sound_volume,
+            Message::SoundVolumeChanged,
+        )
+        .step(0.05)
+        .width(200);
+        let sound_theme_picker = iced::widget::pick_list(
+            SoundTheme::ALL,
+            Some(self.preferences.sound_theme),
+            Message::SoundThemeChanged,
+        );
+        let postpone_slider = iced::widget::slider(
+            1.0..=15.0,
+            self.preferences.postpone_duration / MINUTE,
+            Message::PostponeDurationChanged,
+        )
+        .step(1)
+        .width(200);
+
+        let max_postpones = self.preferences.max_postpones;
+        let max_postpones_stepper = row![
+            button("-").on_press(Message::MaxPostponesChanged(max_postpones.saturating_sub(1))),
+            Text::new(format!("{max_postpones}")),
+            button("+").on_press(Message::MaxPostponesChanged(max_postpones + 1)),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
         let work_value = self.preferences.work_interval / MINUTE;
         let work_widget = row![Text::new(format!("{work_value} minutes"))].padding([0, 10]);
         let break_value = self.preferences.break_interval / MINUTE;
         let break_label = row![Text::new(format!("{break_value} minutes"))].padding([0, 10]);
+        let notifier_picker = iced::widget::pick_list(
+            NotifierKindTag::ALL,
+            Some(NotifierKindTag::from(&self.preferences.notifier)),
+            Message::NotifierKindChanged,
+        );
+        let notifier_settings: Element<Message> =
+            if let NotifierKind::Command { template } = &self.preferences.notifier {
+                Column::new()
+                    .spacing(10)
+                    .push(row![Text::new("Notifier"), notifier_picker])
+                    .push(
+                        text_input("shell command, use {msg} for the alert text", template)
+                            .on_input(Message::NotifierCommandTemplateChanged),
+                    )
+                    .into()
+            } else {
+                row![Text::new("Notifier"), notifier_picker].into()
+            };
+
         let close_button = button("Close").on_press(Message::ToggleSettings);
         Column::new()
             .spacing(20)
@@ -273,8 +662,26 @@ impl Earlygirl {
             .push(row![work_slider, work_widget,])
             .push(Text::new("Set Break Time"))
             .push(row![break_slider, break_label,])
+            .push(Text::new("Set Long Break Time"))
+            .push(row![
+                long_break_slider,
+                Text::new(format!("{} minutes", self.preferences.long_break_interval / MINUTE)),
+            ])
+            .push(Text::new("Pomodoros Until Long Break"))
+            .push(round_size_stepper)
             .push(auto_start_work)
             .push(auto_start_break)
+            .push(sound_enabled)
+            .push(row![Text::new("Volume"), sound_volume_slider])
+            .push(row![Text::new("Sound"), sound_theme_picker])
+            .push(Text::new("Postpone Duration"))
+            .push(row![
+                postpone_slider,
+                Text::new(format!("{} minutes", self.preferences.postpone_duration / MINUTE)),
+            ])
+            .push(Text::new("Max Postpones"))
+            .push(max_postpones_stepper)
+            .push(notifier_settings)
             .push(close_button)
             .into()
     }
@@ -304,6 +711,14 @@ impl Earlygirl {
             timer_button(label, || Message::ToggleSettings)
         };
 
+        let stats_button = {
+            let label = match self.show_stats_modal {
+                true => "Hide Stats",
+                false => "Show Stats",
+            };
+            timer_button(label, || Message::ToggleStats)
+        };
+
         let start_pause_button = {
             let label = match self.timer_state {
                 TimerState::Idle => "Start",
@@ -316,6 +731,11 @@ impl Earlygirl {
 
         let switch_timer_type_button = timer_button("Switch", || Message::SwitchWorkType);
 
+        let break_due = matches!(self.timer_type, TimerType::WorkTime)
+            && matches!(self.timer_state, TimerState::Idle)
+            && self.current_timer_duration >= self.interval
+            && self.postpone_count < self.preferences.max_postpones;
+
         let working_label = match self.timer_state {
             TimerState::Ticking { .. } => "Working!",
             TimerState::Idle => "Start Working!",
@@ -325,6 +745,7 @@ impl Earlygirl {
             let label = match self.timer_type {
                 TimerType::WorkTime => working_label,
                 TimerType::BreakTime => "Break Time!",
+                TimerType::LongBreakTime => "Long Break Time!",
             };
             text(label).size(30)
         };
@@ -332,12 +753,16 @@ impl Earlygirl {
         let timer_progress = (self.current_timer_duration / self.interval) * 100.0;
         let progress_bar = progress_bar(0.0..=100.0, timer_progress as f32);
 
-        let row = Row::new()
+        let mut row = Row::new()
             .spacing(20)
             .push(start_pause_button)
             .push(switch_timer_type_button)
             .push(reset_button);
 
+        if break_due {
+            row = row.push(timer_button("Postpone", || Message::Postpone));
+        }
+
         let column = Column::new()
             .align_x(Center)
             .spacing(20)
@@ -346,13 +771,18 @@ impl Earlygirl {
             .push(duration)
             .push(progress_bar)
             .push(row)
-            .push(settings_button);
+            .push(row![settings_button, stats_button].spacing(20));
 
         if self.show_modal {
             let model = container(self.settings_modal())
                 .padding(10)
                 .style(container::rounded_box);
             modal(column, model, Message::ToggleSettings)
+        } else if self.show_stats_modal {
+            let model = container(self.stats_modal())
+                .padding(10)
+                .style(container::rounded_box);
+            modal(column, model, Message::ToggleStats)
         } else {
             Container::new(column)
                 .padding(20)